@@ -1,14 +1,93 @@
 use std::{
+    collections::{HashMap, HashSet, VecDeque},
     fmt,
     io::{self, IoSlice, IoSliceMut, Read, Write},
-    net::SocketAddr,
+    net::{Shutdown, SocketAddr},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    task::{Context, Poll as TaskPoll},
+    thread,
     time::Duration,
 };
 
+pub use futures::io::{AsyncRead, AsyncWrite};
+use futures::stream::Stream as FutureStream;
+use futures::task::AtomicWaker;
+
 pub use libsrt_sys::int;
 use libsrt_sys::{self as sys, Socket};
 pub use libsrt_sys::{EventKind, Events, Token};
 
+/// Live SRT link statistics, as reported by `srt_bstats`.
+///
+/// Counters are either cumulative since the connection was established (or
+/// since the last call with `clear: true`) or instantaneous samples, matching
+/// whichever `srt_bstats` reports them as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// Smoothed round-trip time.
+    pub rtt: Duration,
+    /// Estimated available bandwidth on the link, in bytes/sec.
+    pub estimated_bandwidth: u64,
+    /// Current send rate, in bytes/sec.
+    pub send_rate: u64,
+    /// Current receive rate, in bytes/sec.
+    pub receive_rate: u64,
+    /// Packets sent.
+    pub packets_sent: u64,
+    /// Packets received.
+    pub packets_received: u64,
+    /// Packets lost, on either the send or receive side.
+    pub packets_lost: u64,
+    /// Packets retransmitted.
+    pub packets_retransmitted: u64,
+    /// Packets dropped because they arrived too late to be useful.
+    pub packets_dropped: u64,
+    /// Bytes sent.
+    pub bytes_sent: u64,
+    /// Bytes received.
+    pub bytes_received: u64,
+    /// Current occupancy of the send buffer, in bytes.
+    pub send_buffer_bytes: u64,
+    /// Current occupancy of the receive buffer, in bytes.
+    pub receive_buffer_bytes: u64,
+}
+
+impl Stats {
+    /// Converts the raw `srt_bstats` fields (C ints/doubles, as reported by
+    /// `-sys`) into the typed values above. This mapping lives here, not in
+    /// `libsrt_sys`, so the `-sys` crate stays free of knowledge of this
+    /// layer's types.
+    fn from_raw(raw: sys::RawStats) -> Stats {
+        Stats {
+            rtt: Duration::from_secs_f64((raw.ms_rtt.max(0.0)) / 1_000.0),
+            estimated_bandwidth: mbps_to_bytes_per_sec(raw.mbps_bandwidth),
+            send_rate: mbps_to_bytes_per_sec(raw.mbps_send_rate),
+            receive_rate: mbps_to_bytes_per_sec(raw.mbps_recv_rate),
+            packets_sent: raw.pkt_sent as u64,
+            packets_received: raw.pkt_recv as u64,
+            packets_lost: (raw.pkt_snd_loss + raw.pkt_rcv_loss) as u64,
+            packets_retransmitted: raw.pkt_retrans as u64,
+            packets_dropped: (raw.pkt_snd_drop + raw.pkt_rcv_drop) as u64,
+            bytes_sent: raw.byte_sent as u64,
+            bytes_received: raw.byte_recv as u64,
+            // `byte_avail_snd_buf`/`byte_avail_rcv_buf` are the *free* space
+            // remaining in each buffer; occupancy is the complementary
+            // `byte_snd_buf`/`byte_rcv_buf` pair.
+            send_buffer_bytes: raw.byte_snd_buf as u64,
+            receive_buffer_bytes: raw.byte_rcv_buf as u64,
+        }
+    }
+}
+
+/// Converts a `srt_bstats` Mbps rate (reported as a C double) into bytes/sec.
+fn mbps_to_bytes_per_sec(mbps: f64) -> u64 {
+    ((mbps.max(0.0) * 1_000_000.0) / 8.0) as u64
+}
+
 pub trait AsSocket {
     /// Returns the internal socket.
     fn as_socket(&self) -> &Socket;
@@ -16,6 +95,17 @@ pub trait AsSocket {
     fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.as_socket().take_error()
     }
+
+    /// Returns a snapshot of this connection's live statistics.
+    ///
+    /// When `clear` is `true`, cumulative counters are reset after being
+    /// read, so the next call reports only what happened since this one;
+    /// instantaneous fields like `rtt` and buffer occupancy are unaffected
+    /// either way. Useful for driving adaptive-bitrate logic or monitoring
+    /// over a live link.
+    fn statistics(&self, clear: bool) -> io::Result<Stats> {
+        self.as_socket().bstats(clear).map(Stats::from_raw)
+    }
 }
 
 pub trait Bind: AsSocket {
@@ -36,15 +126,54 @@ pub trait Connect: Bind {
 // SRT builder
 ////////////////////////////////////////////////////////////////////////////////
 
+/// SRT transmission mode, selecting between live, file, and message
+/// semantics (`SRTO_TRANSTYPE`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmissionType {
+    /// Low-latency, loss-tolerant streaming of a continuous media feed.
+    Live,
+    /// Reliable bulk transfer of a file-like byte stream.
+    File,
+    /// Reliable transfer of discrete, boundary-preserving messages.
+    Message,
+}
+
+/// Maps `TransmissionType` to the raw `SRTO_TRANSTYPE` value `-sys` expects.
+/// This mapping lives in `net`, not `libsrt_sys`, for the same reason
+/// `Stats::from_raw` does: `-sys` should not need to know this layer's
+/// types.
+fn transmission_type_to_raw(transmission_type: TransmissionType) -> int {
+    match transmission_type {
+        TransmissionType::Live => 0,
+        TransmissionType::File | TransmissionType::Message => 1,
+    }
+}
+
 /// Builder struct for a SRT instance
 pub struct Builder {
     nonblocking: bool,
+    latency: Option<Duration>,
+    passphrase: Option<String>,
+    pbkeylen: Option<i32>,
+    stream_id: Option<String>,
+    message_api: Option<bool>,
+    transmission_type: Option<TransmissionType>,
+    max_bandwidth: Option<i64>,
+    linger: Option<Duration>,
 }
 
 impl Builder {
     pub fn new() -> Self {
         Builder {
             nonblocking: false,
+            latency: None,
+            passphrase: None,
+            pbkeylen: None,
+            stream_id: None,
+            message_api: None,
+            transmission_type: None,
+            max_bandwidth: None,
+            linger: None,
         }
     }
 
@@ -54,11 +183,125 @@ impl Builder {
         self
     }
 
+    /// Sets the SRT latency applied to both ends of the connection
+    /// (`SRTO_RCVLATENCY`/`SRTO_PEERLATENCY`).
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Sets the passphrase used to enable AES encryption (`SRTO_PASSPHRASE`).
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Some(passphrase.to_owned());
+        self
+    }
+
+    /// Sets the AES key length in bytes, one of 16, 24, or 32
+    /// (`SRTO_PBKEYLEN`).
+    pub fn pbkeylen(mut self, pbkeylen: i32) -> Self {
+        self.pbkeylen = Some(pbkeylen);
+        self
+    }
+
+    /// Sets the stream ID (`SRTO_STREAMID`), used by listener-side demuxers
+    /// to route an incoming connection.
+    pub fn stream_id(mut self, stream_id: &str) -> Self {
+        self.stream_id = Some(stream_id.to_owned());
+        self
+    }
+
+    /// Enables or disables the message API (`SRTO_MESSAGEAPI`).
+    pub fn message_api(mut self, message_api: bool) -> Self {
+        self.message_api = Some(message_api);
+        self
+    }
+
+    /// Sets the transmission type (`SRTO_TRANSTYPE`).
+    pub fn transmission_type(mut self, transmission_type: TransmissionType) -> Self {
+        self.transmission_type = Some(transmission_type);
+        self
+    }
+
+    /// Sets the maximum bandwidth in bytes/sec, or a negative value for
+    /// unlimited (`SRTO_MAXBW`).
+    pub fn max_bandwidth(mut self, max_bandwidth: i64) -> Self {
+        self.max_bandwidth = Some(max_bandwidth);
+        self
+    }
+
+    /// Sets how long `Stream::shutdown(Write)` will block waiting for queued
+    /// data to drain before the connection is closed (`SRTO_LINGER`).
+    /// Dropping a `Stream` does not wait: its drop sets the linger to zero
+    /// before shutting down, so data still queued at that point is not
+    /// drained.
+    pub fn linger(mut self, linger: Duration) -> Self {
+        self.linger = Some(linger);
+        self
+    }
+
+    /// Applies every option set on this builder to `sock`.
+    fn apply_options(&self, sock: &Socket) -> io::Result<()> {
+        if let Some(latency) = self.latency {
+            sock.set_rcv_latency(latency)?;
+            sock.set_peer_latency(latency)?;
+        }
+
+        if let Some(ref passphrase) = self.passphrase {
+            if passphrase.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "SRT passphrase must not be empty",
+                ));
+            }
+            sock.set_passphrase(passphrase)?;
+        }
+
+        if let Some(pbkeylen) = self.pbkeylen {
+            if !matches!(pbkeylen, 16 | 24 | 32) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "SRT pbkeylen must be 16, 24, or 32",
+                ));
+            }
+            sock.set_pbkeylen(pbkeylen)?;
+        }
+
+        if let Some(ref stream_id) = self.stream_id {
+            sock.set_stream_id(stream_id)?;
+        }
+
+        if let Some(message_api) = self.message_api {
+            sock.set_message_api(message_api)?;
+        }
+
+        if let Some(transmission_type) = self.transmission_type {
+            sock.set_transmission_type(transmission_type_to_raw(transmission_type))?;
+
+            // `Message` shares `File`'s raw transmission type; the message
+            // API is what preserves message boundaries, so enable it unless
+            // the caller chose otherwise via `message_api`.
+            if transmission_type == TransmissionType::Message && self.message_api.is_none() {
+                sock.set_message_api(true)?;
+            }
+        }
+
+        if let Some(max_bandwidth) = self.max_bandwidth {
+            sock.set_max_bandwidth(max_bandwidth)?;
+        }
+
+        if let Some(linger) = self.linger {
+            sock.set_linger(linger)?;
+        }
+
+        Ok(())
+    }
+
     /// Opens a SRT connection to a remote host.
     pub fn connect(&self, addr: &SocketAddr) -> io::Result<Stream> {
         sys::init();
 
         let sock = Socket::new(addr)?;
+        self.apply_options(&sock)?;
 
         if self.nonblocking {
             sock.set_send_nonblocking(true)?;
@@ -75,12 +318,55 @@ impl Builder {
         Ok(Stream { sock: sock })
     }
 
+    /// Opens a SRT rendezvous connection: both peers call this
+    /// simultaneously, each bound to its own `local` address and dialing the
+    /// other's `remote` address, establishing a direct connection without
+    /// either side acting as a listener. This is how two SRT endpoints
+    /// behind NAT reach each other.
+    pub fn rendezvous(&self, local: &SocketAddr, remote: &SocketAddr) -> io::Result<Stream> {
+        sys::init();
+
+        if local.is_ipv4() != remote.is_ipv4() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "rendezvous requires local and remote addresses of the same family",
+            ));
+        }
+
+        if local.port() == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "rendezvous requires a bound local port",
+            ));
+        }
+
+        let sock = Socket::new(remote)?;
+        self.apply_options(&sock)?;
+        sock.set_rendezvous(true)?;
+        sock.bind(local)?;
+
+        if self.nonblocking {
+            sock.set_send_nonblocking(true)?;
+            sock.set_recv_nonblocking(true)?;
+            match sock.connect(remote) {
+                Ok(_) => {}
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                Err(e) => return Err(e),
+            }
+        } else {
+            sock.connect(remote)?;
+        }
+
+        Ok(Stream { sock: sock })
+    }
+
     /// Creates a new `Listener` which will be bound to the specified
     /// address.
     pub fn bind(&self, addr: &SocketAddr) -> io::Result<Listener> {
         sys::init();
 
         let sock = Socket::new(addr)?;
+        self.apply_options(&sock)?;
         sock.bind(addr)?;
         sock.listen(128)?;
 
@@ -102,7 +388,6 @@ impl Builder {
     }
 }
 
-
 ////////////////////////////////////////////////////////////////////////////////
 // SRT streams
 ////////////////////////////////////////////////////////////////////////////////
@@ -112,6 +397,18 @@ pub struct Stream {
     sock: Socket,
 }
 
+impl Stream {
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// Shutting down the write half (or both) lets the peer observe a clean
+    /// EOF on its next `read`; since SRT must drain its send buffer first,
+    /// this blocks until the configured linger timeout elapses (or, in
+    /// nonblocking mode, returns `WouldBlock` until it has).
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.sock.shutdown(how)
+    }
+}
+
 impl AsSocket for Stream {
     fn as_socket(&self) -> &Socket {
         &self.sock
@@ -192,13 +489,27 @@ impl fmt::Debug for Stream {
     }
 }
 
+impl Drop for Stream {
+    fn drop(&mut self) {
+        // `shutdown` blocks draining the send buffer for up to the
+        // configured `SRTO_LINGER` (180s by default if the caller never set
+        // one via `Builder::linger`), and `Drop` must never block a thread
+        // for that long. Force the linger to zero for the drop path itself;
+        // callers that want a graceful, bounded drain should call
+        // `shutdown` explicitly (with their own linger configured) before
+        // the `Stream` goes out of scope.
+        let _ = self.sock.set_linger(Duration::ZERO);
+        let _ = self.shutdown(Shutdown::Both);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // SRT listeners
 ////////////////////////////////////////////////////////////////////////////////
 
 /// A SRT input socket server, listening for connections.
 pub struct Listener {
-    sock: Socket
+    sock: Socket,
 }
 
 impl Listener {
@@ -207,6 +518,30 @@ impl Listener {
         let (sock, addr) = self.as_socket().accept()?;
         Ok((Stream { sock: sock }, addr))
     }
+
+    /// Returns an iterator over the connections being received on this
+    /// listener, discarding each peer's address.
+    ///
+    /// The returned iterator will never return `None`; call `accept`
+    /// directly if the peer address is needed or the loop should terminate
+    /// on error.
+    pub fn incoming(&self) -> Incoming<'_> {
+        Incoming { listener: self }
+    }
+
+    /// Combines this listener with `other` so connections on either can be
+    /// accepted through a single call.
+    pub fn join(self, other: Listener) -> io::Result<JoinedListener> {
+        let poll = sys::Poll::new()?;
+        poll.register(self.as_socket(), Token(0), EventKind::Readable)?;
+        poll.register(other.as_socket(), Token(1), EventKind::Readable)?;
+
+        Ok(JoinedListener {
+            first: self,
+            second: other,
+            poll,
+        })
+    }
 }
 
 impl AsSocket for Listener {
@@ -229,6 +564,21 @@ impl fmt::Debug for Listener {
     }
 }
 
+/// An iterator that infinitely accepts connections on a `Listener`.
+///
+/// This struct is created by the [`Listener::incoming`] method.
+pub struct Incoming<'a> {
+    listener: &'a Listener,
+}
+
+impl<'a> Iterator for Incoming<'a> {
+    type Item = io::Result<Stream>;
+
+    fn next(&mut self) -> Option<io::Result<Stream>> {
+        Some(self.listener.accept().map(|(stream, _)| stream))
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // SRT Poll
 ////////////////////////////////////////////////////////////////////////////////
@@ -275,3 +625,707 @@ impl Poll {
         self.poll.poll(events, timeout)
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// SRT connection bonding
+////////////////////////////////////////////////////////////////////////////////
+
+/// How a [`Group`] combines its member streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupMode {
+    /// Every member carries every packet; writes go out on all members and
+    /// reads dedupe by SRT sequence number.
+    Broadcast,
+    /// The first member is primary; the rest take over transparently if it
+    /// fails.
+    Backup,
+}
+
+/// A bounded set of recently observed SRT sequence numbers.
+///
+/// Broadcast group members can deliver the same packet out of order (one
+/// link is briefly ahead of another), so dedup can't rely on comparing
+/// against just the last sequence number returned; this keeps a sliding
+/// window of everything seen recently instead.
+struct SeqWindow {
+    seen: HashSet<i64>,
+    order: VecDeque<i64>,
+    capacity: usize,
+}
+
+impl SeqWindow {
+    fn new(capacity: usize) -> SeqWindow {
+        SeqWindow {
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Records `seqno`, returning `true` the first time it is seen and
+    /// `false` if it is still within the window from an earlier call.
+    fn insert(&mut self, seqno: i64) -> bool {
+        if !self.seen.insert(seqno) {
+            return false;
+        }
+
+        self.order.push_back(seqno);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+/// Several connected [`Stream`]s bonded into one logical connection, for
+/// redundancy ([`Group::broadcast`]) or failover ([`Group::backup`]).
+///
+/// Reads are driven by an internal [`sys::Poll`] registration per member, so
+/// a member with nothing to deliver never blocks the others.
+pub struct Group {
+    mode: GroupMode,
+    members: Vec<Stream>,
+    poll: sys::Poll,
+    events: Events,
+    seen: SeqWindow,
+    /// Indices of members that have returned a hard (non-`WouldBlock`)
+    /// error and been dropped from rotation. Members stay in `members` at
+    /// their original index (so `Token(index)` stays valid) but are never
+    /// polled or read from again once dead.
+    dead: HashSet<usize>,
+}
+
+impl Group {
+    /// Combines `streams` into a broadcast group: every write is replicated
+    /// to every member, and reads dedupe by SRT sequence number so the
+    /// caller sees each packet exactly once regardless of which link
+    /// delivered it first.
+    pub fn broadcast(streams: Vec<Stream>) -> io::Result<Group> {
+        Group::new(GroupMode::Broadcast, streams)
+    }
+
+    /// Combines `streams` into a backup group: the first member carries the
+    /// stream, and the rest take over should it fail.
+    pub fn backup(streams: Vec<Stream>) -> io::Result<Group> {
+        Group::new(GroupMode::Backup, streams)
+    }
+
+    fn new(mode: GroupMode, streams: Vec<Stream>) -> io::Result<Group> {
+        let poll = sys::Poll::new()?;
+
+        for (index, stream) in streams.iter().enumerate() {
+            stream.sock.set_recv_nonblocking(true)?;
+            poll.register(&stream.sock, Token(index), EventKind::Readable)?;
+        }
+
+        let events = Events::with_capacity(streams.len().max(1));
+
+        Ok(Group {
+            mode,
+            members: streams,
+            poll,
+            events,
+            seen: SeqWindow::new(4096),
+            dead: HashSet::new(),
+        })
+    }
+
+    /// Returns the member streams making up this group.
+    pub fn members(&self) -> &[Stream] {
+        &self.members
+    }
+}
+
+impl Read for Group {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if self.dead.len() == self.members.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "all group members have failed",
+                ));
+            }
+
+            self.poll.poll(&mut self.events, None)?;
+
+            for event in self.events.iter() {
+                let Token(index) = event.token();
+                if self.dead.contains(&index) {
+                    continue;
+                }
+                let member = match self.members.get_mut(index) {
+                    Some(member) => member,
+                    None => continue,
+                };
+
+                match member.sock.recv_seqno(buf) {
+                    Ok((n, seqno)) => {
+                        if self.mode == GroupMode::Broadcast && !self.seen.insert(seqno) {
+                            continue;
+                        }
+                        return Ok(n);
+                    }
+                    Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                    Err(_) => {
+                        // A hard error on one member (the peer dropped that
+                        // link, say) must not kill the whole group — that
+                        // would defeat the point of bonding. Drop just this
+                        // member and keep reading from the rest.
+                        let _ = self.poll.deregister(&member.sock);
+                        self.dead.insert(index);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Write for Group {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut written = None;
+        let mut last_err = None;
+        let mut short = false;
+
+        for member in &mut self.members {
+            match member.write(buf) {
+                Ok(n) => {
+                    short |= n < buf.len();
+                    written = Some(n);
+                    if self.mode == GroupMode::Backup {
+                        break;
+                    }
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+
+        // In broadcast mode every member must accept the full buffer: a
+        // short write on one link, reported back as a success, would let
+        // that member's copy of the replicated data silently diverge from
+        // the rest.
+        if self.mode == GroupMode::Broadcast && short {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "broadcast group member accepted a short write",
+            ));
+        }
+
+        written.ok_or_else(|| {
+            last_err
+                .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "no group members"))
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for member in &mut self.members {
+            member.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifies which listener of a [`JoinedListener`] a connection arrived
+/// on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Which {
+    First,
+    Second,
+}
+
+/// Two listeners accepted through one call, as if they were a single
+/// acceptor. Created by [`Listener::join`].
+pub struct JoinedListener {
+    first: Listener,
+    second: Listener,
+    poll: sys::Poll,
+}
+
+impl JoinedListener {
+    /// Blocks until a connection is available on either listener, then
+    /// accepts it, reporting which listener it arrived on.
+    pub fn accept(&self) -> io::Result<(Stream, SocketAddr, Which)> {
+        let mut events = Events::with_capacity(2);
+
+        loop {
+            self.poll.poll(&mut events, None)?;
+
+            for event in events.iter() {
+                let (listener, which) = match event.token() {
+                    Token(0) => (&self.first, Which::First),
+                    Token(1) => (&self.second, Which::Second),
+                    _ => continue,
+                };
+
+                match listener.accept() {
+                    Ok((stream, addr)) => return Ok((stream, addr, which)),
+                    Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SRT async support
+////////////////////////////////////////////////////////////////////////////////
+
+/// Per-token read/write waiters, woken by the background reactor thread when
+/// the corresponding `EventKind` fires.
+///
+/// `read_ready`/`write_ready` latch a fired event until the waiting future
+/// observes it, so a wakeup that races with `Readiness::poll` registering its
+/// waker is not lost.
+///
+/// `write_armed` tracks whether the shared token (see `AsyncStream`) is
+/// currently registered for `Writable` in addition to the always-on
+/// `Readable`: a connected socket is writable almost all of the time under
+/// level-triggered epoll, so `Writable` is armed one-shot, only while a
+/// writer is actually parked, rather than left registered permanently.
+#[derive(Default)]
+struct Interest {
+    read: AtomicWaker,
+    write: AtomicWaker,
+    read_ready: AtomicBool,
+    write_ready: AtomicBool,
+    write_armed: AtomicBool,
+}
+
+/// Reregisters `token` for `Readable`, preserving any outstanding `Writable`
+/// arm so this never clobbers a concurrently parked writer.
+fn rearm_read(socket: &Socket, token: Token, interest: &Interest) {
+    let kind = if interest.write_armed.load(Ordering::Acquire) {
+        EventKind::Readable | EventKind::Writable
+    } else {
+        EventKind::Readable
+    };
+    let _ = Reactor::get().poll.reregister(socket, token, kind);
+}
+
+/// Arms `Writable` interest (in addition to the always-on `Readable`) while a
+/// writer parks.
+fn arm_write(socket: &Socket, token: Token, interest: &Interest) {
+    interest.write_armed.store(true, Ordering::Release);
+    let _ =
+        Reactor::get()
+            .poll
+            .reregister(socket, token, EventKind::Readable | EventKind::Writable);
+}
+
+/// Disarms `Writable` interest once a parked writer has been woken (or never
+/// needed to park at all).
+fn disarm_write(socket: &Socket, token: Token, interest: &Interest) {
+    if interest.write_armed.swap(false, Ordering::AcqRel) {
+        let _ = Reactor::get()
+            .poll
+            .reregister(socket, token, EventKind::Readable);
+    }
+}
+
+/// A single background thread driving a `sys::Poll` for every registered
+/// async socket. There is one reactor for the whole process; sockets
+/// register and deregister themselves as they are created and dropped.
+struct Reactor {
+    poll: sys::Poll,
+    interests: Mutex<HashMap<Token, Arc<Interest>>>,
+    next_token: Mutex<usize>,
+}
+
+impl Reactor {
+    fn get() -> &'static Reactor {
+        static REACTOR: OnceLock<Reactor> = OnceLock::new();
+        REACTOR.get_or_init(|| {
+            let poll = sys::Poll::new().expect("failed to create SRT reactor poll");
+
+            thread::Builder::new()
+                .name("srt-reactor".to_owned())
+                .spawn(Reactor::run)
+                .expect("failed to spawn SRT reactor thread");
+
+            Reactor {
+                poll,
+                interests: Mutex::new(HashMap::new()),
+                next_token: Mutex::new(0),
+            }
+        })
+    }
+
+    fn run() {
+        let reactor = Reactor::get();
+        let mut events = Events::with_capacity(1024);
+
+        loop {
+            // `poll` errors immediately (rather than blocking) when the
+            // registration set is empty and on other transient failures;
+            // back off instead of spinning a core on the retry.
+            if reactor.poll.poll(&mut events, None).is_err() {
+                thread::sleep(Duration::from_millis(10));
+                continue;
+            }
+
+            let interests = reactor.interests.lock().unwrap();
+            for event in events.iter() {
+                let interest = match interests.get(&event.token()) {
+                    Some(interest) => interest,
+                    None => continue,
+                };
+
+                match event.kind() {
+                    EventKind::Readable => {
+                        interest.read_ready.store(true, Ordering::Release);
+                        interest.read.wake();
+                    }
+                    EventKind::Writable => {
+                        interest.write_ready.store(true, Ordering::Release);
+                        interest.write.wake();
+                    }
+                }
+            }
+        }
+    }
+
+    fn alloc_token(&self) -> Token {
+        let mut next_token = self.next_token.lock().unwrap();
+        let token = Token(*next_token);
+        *next_token += 1;
+        token
+    }
+
+    fn register(
+        &self,
+        socket: &Socket,
+        token: Token,
+        event: EventKind,
+    ) -> io::Result<Arc<Interest>> {
+        let interest = Arc::new(Interest::default());
+        self.poll.register(socket, token, event)?;
+        self.interests
+            .lock()
+            .unwrap()
+            .insert(token, interest.clone());
+        Ok(interest)
+    }
+
+    fn deregister(&self, socket: &Socket, token: Token) {
+        let _ = self.poll.deregister(socket);
+        self.interests.lock().unwrap().remove(&token);
+    }
+}
+
+/// A future that resolves once `socket` becomes readable or writable
+/// (according to `want`), as reported by the background reactor.
+struct Readiness<'a> {
+    socket: &'a Socket,
+    token: Token,
+    interest: &'a Interest,
+    want: EventKind,
+}
+
+impl<'a> Readiness<'a> {
+    /// Called once this future is about to resolve: releases the one-shot
+    /// `Writable` arm so a socket with no parked writer stops being
+    /// reported as writable on every reactor poll.
+    fn on_woken(&self) {
+        if let EventKind::Writable = self.want {
+            disarm_write(self.socket, self.token, self.interest);
+        }
+    }
+
+    /// Called when this future is about to park: arms whichever interest it
+    /// is waiting on.
+    fn arm(&self) {
+        match self.want {
+            EventKind::Readable => rearm_read(self.socket, self.token, self.interest),
+            EventKind::Writable => arm_write(self.socket, self.token, self.interest),
+        }
+    }
+}
+
+impl<'a> std::future::Future for Readiness<'a> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> TaskPoll<()> {
+        let (waker, ready) = match self.want {
+            EventKind::Readable => (&self.interest.read, &self.interest.read_ready),
+            EventKind::Writable => (&self.interest.write, &self.interest.write_ready),
+        };
+
+        if ready.swap(false, Ordering::AcqRel) {
+            self.on_woken();
+            return TaskPoll::Ready(());
+        }
+
+        waker.register(cx.waker());
+
+        // Re-check after registering: the reactor may have latched
+        // readiness between the check above and the waker registration.
+        if ready.swap(false, Ordering::AcqRel) {
+            self.on_woken();
+            return TaskPoll::Ready(());
+        }
+
+        // Re-register so a readiness event that fired between the last poll
+        // and this registration is not missed.
+        self.arm();
+
+        TaskPoll::Pending
+    }
+}
+
+/// An asynchronous SRT stream, driven by a background reactor thread.
+///
+/// Unlike [`Stream`], reads and writes never block the calling task; instead
+/// they return [`Poll::Pending`](std::task::Poll::Pending) and schedule a
+/// wakeup once the socket becomes ready.
+pub struct AsyncStream {
+    sock: Socket,
+    token: Token,
+    interest: Arc<Interest>,
+}
+
+impl AsyncStream {
+    fn new(sock: Socket) -> io::Result<AsyncStream> {
+        sock.set_send_nonblocking(true)?;
+        sock.set_recv_nonblocking(true)?;
+
+        // Read and write interest are multiplexed through a single
+        // registration: `sys::Poll` has no documented support for
+        // registering one socket under two independent tokens, so both
+        // directions share this one, with `Writable` armed one-shot only
+        // while a writer is parked (see `arm_write`/`disarm_write`) rather
+        // than left permanently registered.
+        let reactor = Reactor::get();
+        let token = reactor.alloc_token();
+        let interest = reactor.register(&sock, token, EventKind::Readable)?;
+
+        Ok(AsyncStream {
+            sock,
+            token,
+            interest,
+        })
+    }
+
+    /// Returns a future that resolves once this stream is readable.
+    pub fn readable(&self) -> impl std::future::Future<Output = ()> + '_ {
+        Readiness {
+            socket: &self.sock,
+            token: self.token,
+            interest: &self.interest,
+            want: EventKind::Readable,
+        }
+    }
+
+    /// Returns a future that resolves once this stream is writable.
+    pub fn writable(&self) -> impl std::future::Future<Output = ()> + '_ {
+        Readiness {
+            socket: &self.sock,
+            token: self.token,
+            interest: &self.interest,
+            want: EventKind::Writable,
+        }
+    }
+}
+
+impl AsSocket for AsyncStream {
+    fn as_socket(&self) -> &Socket {
+        &self.sock
+    }
+}
+
+impl Bind for AsyncStream {}
+
+impl Connect for AsyncStream {}
+
+impl AsyncRead for AsyncStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> TaskPoll<io::Result<usize>> {
+        loop {
+            match self.sock.recv(buf) {
+                Ok(n) => return TaskPoll::Ready(Ok(n)),
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.interest.read.register(cx.waker());
+                    rearm_read(&self.sock, self.token, &self.interest);
+
+                    // A readiness event may have fired between the failed
+                    // `recv` above and registering the waker; if so, retry
+                    // now instead of parking on a wakeup that already
+                    // happened.
+                    if self.interest.read_ready.swap(false, Ordering::AcqRel) {
+                        continue;
+                    }
+
+                    return TaskPoll::Pending;
+                }
+                Err(e) => return TaskPoll::Ready(Err(e)),
+            }
+        }
+    }
+}
+
+impl AsyncWrite for AsyncStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> TaskPoll<io::Result<usize>> {
+        loop {
+            match self.sock.send(buf) {
+                Ok(n) => {
+                    disarm_write(&self.sock, self.token, &self.interest);
+                    return TaskPoll::Ready(Ok(n));
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.interest.write.register(cx.waker());
+                    arm_write(&self.sock, self.token, &self.interest);
+
+                    // See the matching comment in `poll_read`: a write-ready
+                    // event may have already latched before we registered.
+                    if self.interest.write_ready.swap(false, Ordering::AcqRel) {
+                        disarm_write(&self.sock, self.token, &self.interest);
+                        continue;
+                    }
+
+                    return TaskPoll::Pending;
+                }
+                Err(e) => {
+                    disarm_write(&self.sock, self.token, &self.interest);
+                    return TaskPoll::Ready(Err(e));
+                }
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> TaskPoll<io::Result<()>> {
+        TaskPoll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> TaskPoll<io::Result<()>> {
+        TaskPoll::Ready(Ok(()))
+    }
+}
+
+impl Drop for AsyncStream {
+    fn drop(&mut self) {
+        Reactor::get().deregister(&self.sock, self.token);
+    }
+}
+
+/// A SRT listener driven by a background reactor thread.
+pub struct AsyncListener {
+    sock: Socket,
+    token: Token,
+    interest: Arc<Interest>,
+}
+
+impl AsyncListener {
+    fn new(sock: Socket) -> io::Result<AsyncListener> {
+        sock.set_recv_nonblocking(true)?;
+
+        let reactor = Reactor::get();
+        let token = reactor.alloc_token();
+        let interest = reactor.register(&sock, token, EventKind::Readable)?;
+
+        Ok(AsyncListener {
+            sock,
+            token,
+            interest,
+        })
+    }
+
+    /// Returns a future that resolves to a newly accepted connection once one
+    /// becomes available.
+    pub async fn accept(&self) -> io::Result<(AsyncStream, SocketAddr)> {
+        loop {
+            match self.sock.accept() {
+                Ok((sock, addr)) => return Ok((AsyncStream::new(sock)?, addr)),
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    Readiness {
+                        socket: &self.sock,
+                        token: self.token,
+                        interest: &self.interest,
+                        want: EventKind::Readable,
+                    }
+                    .await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl AsSocket for AsyncListener {
+    fn as_socket(&self) -> &Socket {
+        &self.sock
+    }
+}
+
+impl Bind for AsyncListener {}
+
+impl FutureStream for AsyncListener {
+    type Item = io::Result<(AsyncStream, SocketAddr)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> TaskPoll<Option<Self::Item>> {
+        loop {
+            match self.sock.accept() {
+                Ok((sock, addr)) => {
+                    return TaskPoll::Ready(Some(AsyncStream::new(sock).map(|s| (s, addr))))
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.interest.read.register(cx.waker());
+                    let _ =
+                        Reactor::get()
+                            .poll
+                            .reregister(&self.sock, self.token, EventKind::Readable);
+
+                    if self.interest.read_ready.swap(false, Ordering::AcqRel) {
+                        continue;
+                    }
+
+                    return TaskPoll::Pending;
+                }
+                Err(e) => return TaskPoll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+impl Drop for AsyncListener {
+    fn drop(&mut self) {
+        Reactor::get().deregister(&self.sock, self.token);
+    }
+}
+
+impl Builder {
+    /// Opens an asynchronous SRT connection to a remote host.
+    pub fn connect_async(&self, addr: &SocketAddr) -> io::Result<AsyncStream> {
+        sys::init();
+
+        let sock = Socket::new(addr)?;
+        self.apply_options(&sock)?;
+        match sock.connect(addr) {
+            Ok(_) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e),
+        }
+
+        AsyncStream::new(sock)
+    }
+
+    /// Creates a new `AsyncListener` bound to the specified address.
+    pub fn bind_async(&self, addr: &SocketAddr) -> io::Result<AsyncListener> {
+        sys::init();
+
+        let sock = Socket::new(addr)?;
+        self.apply_options(&sock)?;
+        sock.bind(addr)?;
+        sock.listen(128)?;
+
+        AsyncListener::new(sock)
+    }
+}